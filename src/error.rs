@@ -30,6 +30,17 @@ pub enum DuneError {
     /// Query was cancelled.
     #[error("Query execution was cancelled")]
     Cancelled,
+
+    /// Failed to read or write data (e.g. while exporting to CSV or
+    /// NDJSON, or loading or saving a test fixture).
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Failed to serialize result data while exporting it (e.g. to JSON or
+    /// NDJSON). Kept distinct from [`DuneError::Parse`], which is about
+    /// deserializing an API response rather than producing output.
+    #[error("Failed to export results: {0}")]
+    Export(String),
 }
 
 /// Result type for Dune API operations.
@@ -0,0 +1,10 @@
+//! Rust client for the [Dune Analytics](https://dune.com) API.
+
+pub mod error;
+pub mod pagination;
+pub mod poll;
+#[cfg(feature = "testing")]
+pub mod testing;
+pub mod types;
+
+pub use error::{DuneError, Result};
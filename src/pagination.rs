@@ -0,0 +1,250 @@
+use std::collections::{HashMap, VecDeque};
+
+use futures::stream::{self, Stream};
+
+use crate::error::Result;
+use crate::poll::DuneApi;
+use crate::types::ResultOptions;
+
+/// Page size used when the caller's [`ResultOptions`] doesn't set a `limit`.
+const DEFAULT_PAGE_SIZE: u32 = 1000;
+
+/// Walks every row of an execution's results, fetching pages on demand.
+///
+/// Starts from the `offset` in the `ResultOptions` passed to
+/// [`RowPaginator::new`] and keeps advancing it by the page size until
+/// Dune's reported `total_row_count` is reached or a page comes back
+/// shorter than requested. `sort_by`, `order`, `columns` and `filters` are
+/// preserved across every page fetched.
+pub struct RowPaginator<'a, C: DuneApi> {
+    client: &'a C,
+    execution_id: String,
+    options: ResultOptions,
+    page_size: u32,
+    next_offset: u32,
+    total_row_count: Option<u64>,
+    done: bool,
+}
+
+impl<'a, C: DuneApi> RowPaginator<'a, C> {
+    /// Creates a paginator starting at `options.offset` (default 0), using
+    /// `options.limit` as the page size (default `1000`) and holding the
+    /// rest of `options` constant across every page.
+    pub fn new(client: &'a C, execution_id: impl Into<String>, options: ResultOptions) -> Self {
+        // A limit of 0 would never advance the offset, so treat it the
+        // same as "unset" rather than looping forever.
+        let page_size = match options.limit {
+            None | Some(0) => DEFAULT_PAGE_SIZE,
+            Some(limit) => limit,
+        };
+        let next_offset = options.offset.unwrap_or(0);
+
+        Self {
+            client,
+            execution_id: execution_id.into(),
+            options,
+            page_size,
+            next_offset,
+            total_row_count: None,
+            done: false,
+        }
+    }
+
+    /// Fetches the next page of rows, or `None` once the result set is
+    /// exhausted.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<HashMap<String, serde_json::Value>>>> {
+        if self.done {
+            return Ok(None);
+        }
+
+        let mut page_options = self.options.clone();
+        page_options.limit = Some(self.page_size);
+        page_options.offset = Some(self.next_offset);
+
+        let response = self
+            .client
+            .execution_results(&self.execution_id, &page_options)
+            .await?;
+
+        let Some(result) = response.result else {
+            self.done = true;
+            return Ok(None);
+        };
+
+        self.total_row_count = Some(result.metadata.total_row_count);
+
+        let fetched = result.rows.len() as u64;
+        self.next_offset += self.page_size;
+
+        let exhausted = fetched < self.page_size as u64
+            || self
+                .total_row_count
+                .is_some_and(|total| self.next_offset as u64 >= total);
+        if exhausted {
+            self.done = true;
+        }
+
+        if result.rows.is_empty() {
+            Ok(None)
+        } else {
+            Ok(Some(result.rows))
+        }
+    }
+
+    /// Turns this paginator into a `Stream` that yields one row at a time,
+    /// fetching the next page transparently whenever the current one runs
+    /// out.
+    pub fn into_row_stream(
+        self,
+    ) -> impl Stream<Item = Result<HashMap<String, serde_json::Value>>> + 'a {
+        stream::unfold(
+            (self, VecDeque::new()),
+            |(mut paginator, mut buffer)| async move {
+                loop {
+                    if let Some(row) = buffer.pop_front() {
+                        return Some((Ok(row), (paginator, buffer)));
+                    }
+
+                    match paginator.next_page().await {
+                        Ok(Some(rows)) => buffer.extend(rows),
+                        Ok(None) => return None,
+                        Err(err) => {
+                            // Fuse the stream: a transient error shouldn't
+                            // turn into an unbounded tight retry loop for
+                            // callers that keep polling past the first Err.
+                            paginator.done = true;
+                            return Some((Err(err), (paginator, buffer)));
+                        }
+                    }
+                }
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::error::DuneError;
+    use crate::types::{
+        ExecutionResultsResponse, ExecutionState, ExecutionStatusResponse, ResultData,
+        ResultMetadata,
+    };
+
+    /// A `DuneApi` that returns canned `execution_results` responses in
+    /// order and records the `ResultOptions` each call was made with.
+    struct RecordingClient {
+        responses: Mutex<VecDeque<ExecutionResultsResponse>>,
+        requested_options: Mutex<Vec<ResultOptions>>,
+    }
+
+    impl RecordingClient {
+        fn new(responses: Vec<ExecutionResultsResponse>) -> Self {
+            Self {
+                responses: Mutex::new(responses.into()),
+                requested_options: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl DuneApi for RecordingClient {
+        async fn execution_status(&self, _execution_id: &str) -> Result<ExecutionStatusResponse> {
+            unreachable!("RowPaginator never calls execution_status")
+        }
+
+        async fn execution_results(
+            &self,
+            _execution_id: &str,
+            options: &ResultOptions,
+        ) -> Result<ExecutionResultsResponse> {
+            self.requested_options.lock().unwrap().push(options.clone());
+            self.responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .ok_or_else(|| DuneError::Api {
+                    message: "unexpected extra fetch".to_string(),
+                })
+        }
+    }
+
+    fn row(value: &str) -> HashMap<String, serde_json::Value> {
+        let mut row = HashMap::new();
+        row.insert("a".to_string(), serde_json::json!(value));
+        row
+    }
+
+    fn results_response(
+        rows: Vec<HashMap<String, serde_json::Value>>,
+        total_row_count: u64,
+    ) -> ExecutionResultsResponse {
+        ExecutionResultsResponse {
+            execution_id: "exec-1".to_string(),
+            query_id: None,
+            state: ExecutionState::Completed,
+            submitted_at: None,
+            execution_started_at: None,
+            execution_ended_at: None,
+            expires_at: None,
+            result: Some(ResultData {
+                metadata: ResultMetadata {
+                    column_names: vec!["a".to_string()],
+                    column_types: vec!["varchar".to_string()],
+                    total_row_count,
+                    datapoint_count: rows.len() as u64,
+                    result_set_bytes: None,
+                    pending_time_millis: None,
+                    execution_time_millis: None,
+                },
+                rows,
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn zero_limit_falls_back_to_default_page_size() {
+        let client = RecordingClient::new(vec![results_response(vec![row("x")], 1)]);
+        let mut paginator = RowPaginator::new(&client, "exec-1", ResultOptions::new().limit(0));
+
+        paginator.next_page().await.unwrap();
+
+        let requested = client.requested_options.lock().unwrap();
+        assert_eq!(requested[0].limit, Some(DEFAULT_PAGE_SIZE));
+    }
+
+    #[tokio::test]
+    async fn advances_offset_across_pages_and_stops_on_a_short_page() {
+        let client = RecordingClient::new(vec![
+            results_response(vec![row("a"), row("b")], 3),
+            results_response(vec![row("c")], 3),
+        ]);
+        let mut paginator = RowPaginator::new(&client, "exec-1", ResultOptions::new().limit(2));
+
+        let page1 = paginator.next_page().await.unwrap().unwrap();
+        assert_eq!(page1.len(), 2);
+
+        let page2 = paginator.next_page().await.unwrap().unwrap();
+        assert_eq!(page2.len(), 1);
+
+        // The short page ended the result set, so this must not fetch again.
+        assert_eq!(paginator.next_page().await.unwrap(), None);
+
+        let requested = client.requested_options.lock().unwrap();
+        assert_eq!(requested.len(), 2);
+        assert_eq!(requested[0].offset, Some(0));
+        assert_eq!(requested[1].offset, Some(2));
+    }
+
+    #[tokio::test]
+    async fn stops_once_total_row_count_is_reached_even_on_a_full_page() {
+        let client = RecordingClient::new(vec![results_response(vec![row("a"), row("b")], 2)]);
+        let mut paginator = RowPaginator::new(&client, "exec-1", ResultOptions::new().limit(2));
+
+        let page = paginator.next_page().await.unwrap().unwrap();
+        assert_eq!(page.len(), 2);
+
+        assert_eq!(paginator.next_page().await.unwrap(), None);
+    }
+}
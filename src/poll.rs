@@ -0,0 +1,354 @@
+use std::time::{Duration, Instant};
+
+use crate::error::{DuneError, Result};
+use crate::types::{
+    ExecutionResultsResponse, ExecutionState, ExecutionStatusResponse, ResultFormat, ResultOptions,
+};
+
+/// Methods needed to drive [`wait_for_completion`].
+///
+/// This crate has no HTTP implementation of it; `testing::MockTransport`
+/// (behind the `testing` feature) implements it by replaying canned
+/// responses. Kept as a trait so the polling loop can be exercised against
+/// a mock in tests.
+pub trait DuneApi {
+    /// Fetches the current status of an execution.
+    fn execution_status(
+        &self,
+        execution_id: &str,
+    ) -> impl std::future::Future<Output = Result<ExecutionStatusResponse>> + Send;
+
+    /// Fetches the results of a (presumably completed) execution.
+    fn execution_results(
+        &self,
+        execution_id: &str,
+        options: &ResultOptions,
+    ) -> impl std::future::Future<Output = Result<ExecutionResultsResponse>> + Send;
+
+    /// Fetches results and renders them in the requested [`ResultFormat`].
+    fn fetch_results_as(
+        &self,
+        execution_id: &str,
+        format: ResultFormat,
+        options: &ResultOptions,
+    ) -> impl std::future::Future<Output = Result<String>> + Send
+    where
+        Self: Sync,
+    {
+        async move {
+            let response = self.execution_results(execution_id, options).await?;
+            let result = response.result.ok_or_else(|| DuneError::Api {
+                message: format!("execution {execution_id} has no results"),
+            })?;
+
+            match format {
+                ResultFormat::Json => serde_json::to_string(&result.rows)
+                    .map_err(|e| DuneError::Export(e.to_string())),
+                ResultFormat::Csv => {
+                    let mut buf = Vec::new();
+                    result.to_csv(&mut buf)?;
+                    Ok(String::from_utf8_lossy(&buf).into_owned())
+                }
+                ResultFormat::Ndjson => {
+                    let mut buf = Vec::new();
+                    result.to_ndjson(&mut buf)?;
+                    Ok(String::from_utf8_lossy(&buf).into_owned())
+                }
+            }
+        }
+    }
+}
+
+/// Options controlling how [`wait_for_completion`] polls for execution status.
+#[derive(Debug, Clone)]
+pub struct PollOptions {
+    /// Floor for the delay between polls. The first status check happens
+    /// immediately; this is the wait before the second, and later waits
+    /// grow by `backoff_multiplier` but never drop below it.
+    pub initial_interval: Duration,
+
+    /// Factor the interval is multiplied by after each non-terminal poll.
+    pub backoff_multiplier: f64,
+
+    /// Upper bound on the polling interval, regardless of backoff.
+    pub max_interval: Duration,
+
+    /// Overall deadline for the execution to reach a terminal state.
+    pub timeout: Duration,
+}
+
+impl Default for PollOptions {
+    fn default() -> Self {
+        Self {
+            initial_interval: Duration::from_secs(1),
+            backoff_multiplier: 1.5,
+            max_interval: Duration::from_secs(30),
+            timeout: Duration::from_secs(300),
+        }
+    }
+}
+
+impl PollOptions {
+    /// Creates a new `PollOptions` with default values.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the initial (and minimum) polling interval.
+    pub fn initial_interval(mut self, interval: Duration) -> Self {
+        self.initial_interval = interval;
+        self
+    }
+
+    /// Sets the backoff multiplier applied after each non-terminal poll.
+    pub fn backoff_multiplier(mut self, multiplier: f64) -> Self {
+        self.backoff_multiplier = multiplier;
+        self
+    }
+
+    /// Sets the maximum polling interval.
+    pub fn max_interval(mut self, interval: Duration) -> Self {
+        self.max_interval = interval;
+        self
+    }
+
+    /// Sets the overall timeout.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+/// A snapshot of execution progress, reported to the callback passed to
+/// [`wait_for_completion`].
+#[derive(Debug, Clone)]
+pub struct Progress {
+    /// The state observed in the most recent status fetch.
+    pub state: ExecutionState,
+
+    /// Time elapsed since polling began.
+    pub elapsed: Duration,
+
+    /// Queue position reported by Dune, if the execution is still pending.
+    pub queue_position: Option<u32>,
+
+    /// A rough `0.0..=1.0` estimate of how close the execution is to
+    /// finishing, or `None` when there isn't enough information to guess.
+    ///
+    /// This is a heuristic, not a guarantee from the API: it treats a
+    /// pending execution with a known queue position as "not started" and
+    /// anything executing as halfway done, since Dune doesn't report a
+    /// real completion fraction.
+    pub estimated_fraction: Option<f64>,
+}
+
+impl Progress {
+    fn new(status: &ExecutionStatusResponse, elapsed: Duration) -> Self {
+        let estimated_fraction = match status.state {
+            ExecutionState::Pending if status.queue_position.is_some() => Some(0.0),
+            ExecutionState::Executing => Some(0.5),
+            ExecutionState::Completed => Some(1.0),
+            _ => None,
+        };
+
+        Self {
+            state: status.state,
+            elapsed,
+            queue_position: status.queue_position,
+            estimated_fraction,
+        }
+    }
+}
+
+/// Polls an execution until it reaches a terminal state, then fetches and
+/// returns its results.
+///
+/// Never polls faster than `options.initial_interval`, and always issues
+/// one final status fetch once the timeout has elapsed before giving up,
+/// so a query that finished right as the deadline passed is still reported
+/// as a success rather than a [`DuneError::Timeout`].
+pub async fn wait_for_completion<C, F>(
+    client: &C,
+    execution_id: &str,
+    options: PollOptions,
+    mut on_progress: F,
+) -> Result<ExecutionResultsResponse>
+where
+    C: DuneApi,
+    F: FnMut(Progress),
+{
+    let started = Instant::now();
+    let mut interval = options.initial_interval;
+
+    let status = loop {
+        // Checked before fetching, so a timeout is only reported after a
+        // status fetch that started once the deadline had already passed.
+        let past_deadline = started.elapsed() >= options.timeout;
+
+        let status = client.execution_status(execution_id).await?;
+        on_progress(Progress::new(&status, started.elapsed()));
+
+        if status.state.is_terminal() {
+            break status;
+        }
+
+        if past_deadline {
+            return Err(DuneError::Timeout {
+                seconds: options.timeout.as_secs(),
+            });
+        }
+
+        tokio::time::sleep(interval).await;
+        interval = interval
+            .mul_f64(options.backoff_multiplier)
+            .min(options.max_interval)
+            .max(options.initial_interval);
+    };
+
+    match status.state {
+        ExecutionState::Completed => {
+            client
+                .execution_results(execution_id, &ResultOptions::default())
+                .await
+        }
+        ExecutionState::Failed => Err(DuneError::ExecutionFailed {
+            message: format!("execution {execution_id} failed"),
+        }),
+        ExecutionState::Cancelled => Err(DuneError::Cancelled),
+        ExecutionState::Pending | ExecutionState::Executing => unreachable!(
+            "loop only breaks on a terminal state, got {:?}",
+            status.state
+        ),
+    }
+}
+
+#[cfg(all(test, feature = "testing"))]
+mod tests {
+    use super::*;
+    use crate::testing::{Fixture, MockTransport};
+    use crate::types::{ExecutionResultsResponse, ResultData, ResultMetadata};
+
+    fn status(
+        execution_id: &str,
+        state: ExecutionState,
+        queue_position: Option<u32>,
+    ) -> ExecutionStatusResponse {
+        ExecutionStatusResponse {
+            execution_id: execution_id.to_string(),
+            query_id: None,
+            state,
+            queue_position,
+            submitted_at: None,
+            execution_started_at: None,
+            execution_ended_at: None,
+            expires_at: None,
+        }
+    }
+
+    fn results(execution_id: &str) -> ExecutionResultsResponse {
+        ExecutionResultsResponse {
+            execution_id: execution_id.to_string(),
+            query_id: None,
+            state: ExecutionState::Completed,
+            submitted_at: None,
+            execution_started_at: None,
+            execution_ended_at: None,
+            expires_at: None,
+            result: Some(ResultData {
+                metadata: ResultMetadata {
+                    column_names: vec![],
+                    column_types: vec![],
+                    total_row_count: 0,
+                    datapoint_count: 0,
+                    result_set_bytes: None,
+                    pending_time_millis: None,
+                    execution_time_millis: None,
+                },
+                rows: vec![],
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn reports_progress_and_returns_results_once_completed() {
+        let transport = MockTransport::new(Fixture {
+            statuses: vec![
+                status("exec-1", ExecutionState::Pending, Some(3)),
+                status("exec-1", ExecutionState::Executing, None),
+                status("exec-1", ExecutionState::Completed, None),
+            ],
+            results: Some(results("exec-1")),
+        });
+
+        let mut seen_states = Vec::new();
+        let outcome = wait_for_completion(
+            &transport,
+            "exec-1",
+            PollOptions::new()
+                .initial_interval(Duration::from_millis(1))
+                .max_interval(Duration::from_millis(1)),
+            |progress| seen_states.push(progress.state),
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.execution_id, "exec-1");
+        assert_eq!(
+            seen_states,
+            vec![
+                ExecutionState::Pending,
+                ExecutionState::Executing,
+                ExecutionState::Completed,
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn times_out_if_still_pending_after_the_final_check() {
+        let transport = MockTransport::new(Fixture {
+            statuses: vec![status("exec-1", ExecutionState::Pending, Some(1))],
+            results: None,
+        });
+
+        let err = wait_for_completion(
+            &transport,
+            "exec-1",
+            PollOptions::new()
+                .initial_interval(Duration::from_millis(1))
+                .max_interval(Duration::from_millis(1))
+                .timeout(Duration::from_millis(5)),
+            |_| {},
+        )
+        .await
+        .unwrap_err();
+
+        assert!(matches!(err, DuneError::Timeout { seconds: 0 }));
+    }
+
+    #[tokio::test]
+    async fn a_completion_landing_exactly_at_the_deadline_still_succeeds() {
+        // A long initial_interval paired with a short timeout guarantees
+        // the loop is past `options.timeout` the moment it wakes from its
+        // first sleep, so the fetch after that sleep is the final look.
+        let transport = MockTransport::new(Fixture {
+            statuses: vec![
+                status("exec-1", ExecutionState::Pending, Some(1)),
+                status("exec-1", ExecutionState::Completed, None),
+            ],
+            results: Some(results("exec-1")),
+        });
+
+        let outcome = wait_for_completion(
+            &transport,
+            "exec-1",
+            PollOptions::new()
+                .initial_interval(Duration::from_millis(50))
+                .timeout(Duration::from_millis(10)),
+            |_| {},
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(outcome.execution_id, "exec-1");
+    }
+}
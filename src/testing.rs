@@ -0,0 +1,180 @@
+//! Record/replay mock transport for exercising execution flows (pending ->
+//! executing -> completed) without hitting the live Dune API.
+//!
+//! Gated behind the `testing` feature since it's only useful to downstream
+//! crates writing their own tests against this one.
+
+use std::collections::VecDeque;
+use std::path::Path;
+use std::sync::Mutex;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::Result;
+use crate::poll::DuneApi;
+use crate::types::{ExecutionResultsResponse, ExecutionStatusResponse, ResultOptions};
+
+/// A canned sequence of API responses for a single execution.
+///
+/// `statuses` is replayed in order by successive `execution_status` calls;
+/// once exhausted, the last status keeps being returned so a caller
+/// polling past the captured sequence still converges instead of erroring.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct Fixture {
+    /// Status responses returned by successive `execution_status` calls.
+    pub statuses: Vec<ExecutionStatusResponse>,
+
+    /// The response returned by `execution_results`.
+    pub results: Option<ExecutionResultsResponse>,
+}
+
+impl Fixture {
+    /// Loads a fixture previously written by [`Fixture::save`].
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let data = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&data)?)
+    }
+
+    /// Serializes this fixture to disk so it can be replayed later.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let data = serde_json::to_string_pretty(self)
+            .map_err(|e| crate::error::DuneError::Export(e.to_string()))?;
+        std::fs::write(path, data)?;
+        Ok(())
+    }
+}
+
+/// A [`DuneApi`] implementation that replays a canned [`Fixture`] instead
+/// of calling the live API.
+pub struct MockTransport {
+    statuses: Mutex<VecDeque<ExecutionStatusResponse>>,
+    last_status: Mutex<Option<ExecutionStatusResponse>>,
+    results: Option<ExecutionResultsResponse>,
+}
+
+impl MockTransport {
+    /// Builds a transport that replays the given fixture.
+    pub fn new(fixture: Fixture) -> Self {
+        Self {
+            statuses: Mutex::new(fixture.statuses.into()),
+            last_status: Mutex::new(None),
+            results: fixture.results,
+        }
+    }
+
+    /// Loads a fixture from disk and builds a transport that replays it.
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        Ok(Self::new(Fixture::load(path)?))
+    }
+}
+
+impl DuneApi for MockTransport {
+    async fn execution_status(&self, execution_id: &str) -> Result<ExecutionStatusResponse> {
+        let mut statuses = self.statuses.lock().unwrap();
+        let mut last_status = self.last_status.lock().unwrap();
+
+        let status = match statuses.pop_front().or_else(|| last_status.clone()) {
+            Some(status) => status,
+            None => {
+                return Err(crate::error::DuneError::Api {
+                    message: format!("no fixture status recorded for execution {execution_id}"),
+                })
+            }
+        };
+
+        *last_status = Some(status.clone());
+        Ok(status)
+    }
+
+    async fn execution_results(
+        &self,
+        execution_id: &str,
+        _options: &ResultOptions,
+    ) -> Result<ExecutionResultsResponse> {
+        self.results
+            .clone()
+            .ok_or_else(|| crate::error::DuneError::Api {
+                message: format!("no fixture results recorded for execution {execution_id}"),
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ExecutionState, ResultData, ResultMetadata};
+
+    fn status(state: ExecutionState) -> ExecutionStatusResponse {
+        ExecutionStatusResponse {
+            execution_id: "exec-1".to_string(),
+            query_id: None,
+            state,
+            queue_position: None,
+            submitted_at: None,
+            execution_started_at: None,
+            execution_ended_at: None,
+            expires_at: None,
+        }
+    }
+
+    fn results() -> ExecutionResultsResponse {
+        ExecutionResultsResponse {
+            execution_id: "exec-1".to_string(),
+            query_id: None,
+            state: ExecutionState::Completed,
+            submitted_at: None,
+            execution_started_at: None,
+            execution_ended_at: None,
+            expires_at: None,
+            result: Some(ResultData {
+                metadata: ResultMetadata {
+                    column_names: vec!["a".to_string()],
+                    column_types: vec!["varchar".to_string()],
+                    total_row_count: 1,
+                    datapoint_count: 1,
+                    result_set_bytes: None,
+                    pending_time_millis: None,
+                    execution_time_millis: None,
+                },
+                rows: vec![],
+            }),
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_statuses_in_order_then_repeats_the_last_one_and_returns_fixed_results() {
+        let transport = MockTransport::new(Fixture {
+            statuses: vec![
+                status(ExecutionState::Pending),
+                status(ExecutionState::Executing),
+                status(ExecutionState::Completed),
+            ],
+            results: Some(results()),
+        });
+
+        assert_eq!(
+            transport.execution_status("exec-1").await.unwrap().state,
+            ExecutionState::Pending
+        );
+        assert_eq!(
+            transport.execution_status("exec-1").await.unwrap().state,
+            ExecutionState::Executing
+        );
+        assert_eq!(
+            transport.execution_status("exec-1").await.unwrap().state,
+            ExecutionState::Completed
+        );
+        // The fixture is exhausted, so further polls keep returning the
+        // last status instead of erroring.
+        assert_eq!(
+            transport.execution_status("exec-1").await.unwrap().state,
+            ExecutionState::Completed
+        );
+
+        let fetched = transport
+            .execution_results("exec-1", &ResultOptions::default())
+            .await
+            .unwrap();
+        assert_eq!(fetched.execution_id, results().execution_id);
+    }
+}
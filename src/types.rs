@@ -1,8 +1,85 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+#[cfg(feature = "time")]
+use time::OffsetDateTime;
+
+/// Parses an `Option<String>` execution timestamp as RFC 3339.
+///
+/// Returns `None` when the field itself is absent; an absent field isn't
+/// an error, it just means Dune hasn't reported that timestamp yet.
+#[cfg(feature = "time")]
+fn parse_rfc3339(
+    value: &Option<String>,
+) -> Option<std::result::Result<OffsetDateTime, time::error::Parse>> {
+    value
+        .as_deref()
+        .map(|s| OffsetDateTime::parse(s, &time::format_description::well_known::Rfc3339))
+}
+
+/// Typed timestamp accessors shared by every response that carries the
+/// `submitted_at`/`execution_started_at`/`execution_ended_at`/`expires_at`
+/// quartet of raw RFC 3339 strings.
+///
+/// Implementors only need to supply the four raw-field getters; the parsed
+/// accessors and derived durations are provided once here so a fix to one
+/// (e.g. `time_until_expiry`) automatically applies to every response type.
+#[cfg(feature = "time")]
+pub trait ExecutionTimestamps {
+    /// The raw `submitted_at` field.
+    fn raw_submitted_at(&self) -> &Option<String>;
+
+    /// The raw `execution_started_at` field.
+    fn raw_execution_started_at(&self) -> &Option<String>;
+
+    /// The raw `execution_ended_at` field.
+    fn raw_execution_ended_at(&self) -> &Option<String>;
+
+    /// The raw `expires_at` field.
+    fn raw_expires_at(&self) -> &Option<String>;
+
+    /// Parses `submitted_at` as RFC 3339.
+    fn submitted_at_dt(&self) -> Option<std::result::Result<OffsetDateTime, time::error::Parse>> {
+        parse_rfc3339(self.raw_submitted_at())
+    }
+
+    /// Parses `execution_started_at` as RFC 3339.
+    fn execution_started_at_dt(
+        &self,
+    ) -> Option<std::result::Result<OffsetDateTime, time::error::Parse>> {
+        parse_rfc3339(self.raw_execution_started_at())
+    }
+
+    /// Parses `execution_ended_at` as RFC 3339.
+    fn execution_ended_at_dt(
+        &self,
+    ) -> Option<std::result::Result<OffsetDateTime, time::error::Parse>> {
+        parse_rfc3339(self.raw_execution_ended_at())
+    }
+
+    /// Parses `expires_at` as RFC 3339.
+    fn expires_at_dt(&self) -> Option<std::result::Result<OffsetDateTime, time::error::Parse>> {
+        parse_rfc3339(self.raw_expires_at())
+    }
+
+    /// The wall-clock time the execution took, if it has both started and
+    /// ended and both timestamps parse cleanly.
+    fn execution_duration(&self) -> Option<time::Duration> {
+        let started = self.execution_started_at_dt()?.ok()?;
+        let ended = self.execution_ended_at_dt()?.ok()?;
+        Some(ended - started)
+    }
+
+    /// How long until the results expire, relative to now. Negative if
+    /// they've already expired.
+    fn time_until_expiry(&self) -> Option<time::Duration> {
+        let expires_at = self.expires_at_dt()?.ok()?;
+        Some(expires_at - OffsetDateTime::now_utc())
+    }
+}
+
 /// Parameters for executing a SQL query.
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ExecuteSqlRequest {
     /// The SQL query to execute.
     pub sql: String,
@@ -17,7 +94,7 @@ pub struct ExecuteSqlRequest {
 }
 
 /// Parameters for executing a saved query.
-#[derive(Debug, Clone, Serialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ExecuteQueryRequest {
     /// Optional query parameters to override.
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -43,7 +120,7 @@ pub struct QueryParameter {
 }
 
 /// Response from executing a query.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecuteResponse {
     /// The execution ID to track the query.
     pub execution_id: String,
@@ -53,7 +130,7 @@ pub struct ExecuteResponse {
 }
 
 /// Response from executing a pipeline.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PipelineExecuteResponse {
     /// The pipeline execution ID.
     pub pipeline_execution_id: String,
@@ -102,7 +179,7 @@ impl ExecutionState {
 }
 
 /// Response from getting execution status.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionStatusResponse {
     /// The execution ID.
     pub execution_id: String,
@@ -135,8 +212,120 @@ pub struct ExecutionStatusResponse {
     pub queue_position: Option<u32>,
 }
 
+#[cfg(feature = "time")]
+impl ExecutionTimestamps for ExecutionStatusResponse {
+    fn raw_submitted_at(&self) -> &Option<String> {
+        &self.submitted_at
+    }
+
+    fn raw_execution_started_at(&self) -> &Option<String> {
+        &self.execution_started_at
+    }
+
+    fn raw_execution_ended_at(&self) -> &Option<String> {
+        &self.execution_ended_at
+    }
+
+    fn raw_expires_at(&self) -> &Option<String> {
+        &self.expires_at
+    }
+}
+
+/// The data type of a result column, as reported by Dune.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ColumnType {
+    /// Variable-length character data.
+    Varchar,
+
+    /// Integral number.
+    Integer,
+
+    /// Floating-point number.
+    Double,
+
+    /// Boolean value.
+    Boolean,
+
+    /// Timestamp value.
+    Timestamp,
+
+    /// Array value.
+    Array,
+
+    /// Nested object value.
+    Object,
+
+    /// A column type Dune reports that this crate doesn't know about yet.
+    Unknown(String),
+}
+
+impl ColumnType {
+    fn as_str(&self) -> &str {
+        match self {
+            ColumnType::Varchar => "varchar",
+            ColumnType::Integer => "integer",
+            ColumnType::Double => "double",
+            ColumnType::Boolean => "boolean",
+            ColumnType::Timestamp => "timestamp",
+            ColumnType::Array => "array",
+            ColumnType::Object => "object",
+            ColumnType::Unknown(other) => other,
+        }
+    }
+}
+
+impl From<&str> for ColumnType {
+    fn from(value: &str) -> Self {
+        match value {
+            "varchar" => ColumnType::Varchar,
+            "integer" => ColumnType::Integer,
+            "double" => ColumnType::Double,
+            "boolean" => ColumnType::Boolean,
+            "timestamp" => ColumnType::Timestamp,
+            "array" => ColumnType::Array,
+            "object" => ColumnType::Object,
+            other => ColumnType::Unknown(other.to_string()),
+        }
+    }
+}
+
+impl std::fmt::Display for ColumnType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for ColumnType {
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for ColumnType {
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Ok(ColumnType::from(s.as_str()))
+    }
+}
+
+/// A single column in a result set, pairing its name with its declared type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Column {
+    /// The column name.
+    pub name: String,
+
+    /// The column's declared type.
+    pub type_: ColumnType,
+}
+
 /// Metadata about query results.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResultMetadata {
     /// Column names in the result.
     pub column_names: Vec<String>,
@@ -164,8 +353,40 @@ pub struct ResultMetadata {
     pub execution_time_millis: Option<u64>,
 }
 
+impl ResultMetadata {
+    /// Builds the `(name, type)` view of the schema from `column_names`/`column_types`.
+    ///
+    /// Columns past the end of `column_types` (which Dune may omit) are
+    /// reported as `ColumnType::Unknown`.
+    pub fn columns(&self) -> Vec<Column> {
+        self.column_names
+            .iter()
+            .enumerate()
+            .map(|(i, name)| Column {
+                name: name.clone(),
+                type_: self
+                    .column_types
+                    .get(i)
+                    .map(|t| ColumnType::from(t.as_str()))
+                    .unwrap_or_else(|| ColumnType::Unknown(String::new())),
+            })
+            .collect()
+    }
+
+    /// Looks up the declared type of a column by name.
+    pub fn typed_column(&self, name: &str) -> Option<ColumnType> {
+        let index = self.column_names.iter().position(|n| n == name)?;
+        Some(
+            self.column_types
+                .get(index)
+                .map(|t| ColumnType::from(t.as_str()))
+                .unwrap_or_else(|| ColumnType::Unknown(String::new())),
+        )
+    }
+}
+
 /// Response from getting execution results.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionResultsResponse {
     /// The execution ID.
     pub execution_id: String,
@@ -198,8 +419,66 @@ pub struct ExecutionResultsResponse {
     pub result: Option<ResultData>,
 }
 
+#[cfg(feature = "time")]
+impl ExecutionTimestamps for ExecutionResultsResponse {
+    fn raw_submitted_at(&self) -> &Option<String> {
+        &self.submitted_at
+    }
+
+    fn raw_execution_started_at(&self) -> &Option<String> {
+        &self.execution_started_at
+    }
+
+    fn raw_execution_ended_at(&self) -> &Option<String> {
+        &self.execution_ended_at
+    }
+
+    fn raw_expires_at(&self) -> &Option<String> {
+        &self.expires_at
+    }
+}
+
+/// Output format for exported query results.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResultFormat {
+    /// Rows as a JSON array of objects.
+    Json,
+
+    /// RFC 4180 CSV, with a header row.
+    Csv,
+
+    /// Newline-delimited JSON, one row object per line.
+    Ndjson,
+}
+
+/// Quotes a CSV field per RFC 4180 if it contains a comma, quote or
+/// newline, doubling any embedded quotes.
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Renders a single cell for CSV export, using the column's declared type
+/// to decide how to flatten it rather than dumping raw JSON.
+fn format_cell(value: &serde_json::Value, column_type: &ColumnType) -> String {
+    match (column_type, value) {
+        (_, serde_json::Value::Null) => String::new(),
+        // Varchar and Timestamp both arrive as bare strings; write them
+        // through untouched rather than re-deriving their JSON form.
+        (ColumnType::Varchar | ColumnType::Timestamp, serde_json::Value::String(s)) => s.clone(),
+        (ColumnType::Integer | ColumnType::Double, serde_json::Value::Number(n)) => n.to_string(),
+        (ColumnType::Boolean, serde_json::Value::Bool(b)) => b.to_string(),
+        // A column type that doesn't match the cell's actual JSON shape
+        // (or Array/Object/Unknown) falls back to the raw JSON rendering.
+        _ => value.to_string(),
+    }
+}
+
 /// The actual result data.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ResultData {
     /// Metadata about the results.
     pub metadata: ResultMetadata,
@@ -208,8 +487,81 @@ pub struct ResultData {
     pub rows: Vec<HashMap<String, serde_json::Value>>,
 }
 
+impl ResultData {
+    /// Looks up the declared type of a column by name.
+    ///
+    /// Shorthand for `self.metadata.typed_column(name)`.
+    pub fn typed_column(&self, name: &str) -> Option<ColumnType> {
+        self.metadata.typed_column(name)
+    }
+
+    /// Writes the result set as CSV (RFC 4180), with `column_names` as the
+    /// header row.
+    ///
+    /// Cells are rendered using each column's declared [`ColumnType`]
+    /// rather than dumped as raw JSON, so e.g. strings aren't wrapped in
+    /// extra quotes and `null` becomes an empty field. Fields containing a
+    /// comma, quote or newline are quoted, with embedded quotes doubled.
+    pub fn to_csv<W: std::io::Write>(&self, mut writer: W) -> crate::error::Result<()> {
+        let columns = self.metadata.columns();
+
+        let header = columns
+            .iter()
+            .map(|c| csv_field(&c.name))
+            .collect::<Vec<_>>()
+            .join(",");
+        writeln!(writer, "{header}").map_err(crate::error::DuneError::from)?;
+
+        for row in &self.rows {
+            let line = columns
+                .iter()
+                .map(|c| {
+                    let cell = row.get(&c.name).unwrap_or(&serde_json::Value::Null);
+                    csv_field(&format_cell(cell, &c.type_))
+                })
+                .collect::<Vec<_>>()
+                .join(",");
+            writeln!(writer, "{line}").map_err(crate::error::DuneError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes the result set as newline-delimited JSON, one row object per
+    /// line.
+    pub fn to_ndjson<W: std::io::Write>(&self, mut writer: W) -> crate::error::Result<()> {
+        for row in &self.rows {
+            serde_json::to_writer(&mut writer, row)
+                .map_err(|e| crate::error::DuneError::Export(e.to_string()))?;
+            writeln!(writer).map_err(crate::error::DuneError::from)?;
+        }
+
+        Ok(())
+    }
+
+    /// Deserializes every row into a user-defined type.
+    ///
+    /// Each row is re-encoded as a `serde_json::Value` and deserialized
+    /// through `T`'s `Deserialize` impl, so field renames, defaults and
+    /// `Option` fields behave exactly as they would decoding any other
+    /// JSON object.
+    pub fn deserialize_rows<T>(&self) -> crate::error::Result<Vec<T>>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        self.rows
+            .iter()
+            .map(|row| {
+                let value = serde_json::Value::Object(row.clone().into_iter().collect());
+                serde_json::from_value(value)
+            })
+            .collect::<std::result::Result<Vec<T>, _>>()
+            .map_err(crate::error::DuneError::Parse)
+    }
+}
+
 /// Response from cancelling an execution.
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CancelExecutionResponse {
     /// Whether the cancellation was successful.
     pub success: bool,
@@ -274,6 +626,8 @@ impl ResultOptions {
     }
 
     /// Converts options to query parameters.
+    // Consumed by the HTTP client layer, which isn't part of this crate yet.
+    #[allow(dead_code)]
     pub(crate) fn to_query_params(&self) -> Vec<(String, String)> {
         let mut params = Vec::new();
 
@@ -299,3 +653,159 @@ impl ResultOptions {
         params
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metadata() -> ResultMetadata {
+        ResultMetadata {
+            column_names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            column_types: vec![
+                "varchar".to_string(),
+                "integer".to_string(),
+                "bignum".to_string(),
+            ],
+            total_row_count: 1,
+            datapoint_count: 1,
+            result_set_bytes: None,
+            pending_time_millis: None,
+            execution_time_millis: None,
+        }
+    }
+
+    #[test]
+    fn column_type_roundtrips_known_variants() {
+        for (json, expected) in [
+            ("\"varchar\"", ColumnType::Varchar),
+            ("\"integer\"", ColumnType::Integer),
+            ("\"double\"", ColumnType::Double),
+            ("\"boolean\"", ColumnType::Boolean),
+            ("\"timestamp\"", ColumnType::Timestamp),
+            ("\"array\"", ColumnType::Array),
+            ("\"object\"", ColumnType::Object),
+        ] {
+            let parsed: ColumnType = serde_json::from_str(json).unwrap();
+            assert_eq!(parsed, expected);
+            assert_eq!(serde_json::to_string(&parsed).unwrap(), json);
+        }
+    }
+
+    #[test]
+    fn column_type_falls_back_to_unknown() {
+        let parsed: ColumnType = serde_json::from_str("\"bignum\"").unwrap();
+        assert_eq!(parsed, ColumnType::Unknown("bignum".to_string()));
+        assert_eq!(serde_json::to_string(&parsed).unwrap(), "\"bignum\"");
+    }
+
+    #[test]
+    fn typed_column_reports_schema() {
+        let metadata = metadata();
+        assert_eq!(metadata.typed_column("a"), Some(ColumnType::Varchar));
+        assert_eq!(metadata.typed_column("b"), Some(ColumnType::Integer));
+        assert_eq!(
+            metadata.typed_column("c"),
+            Some(ColumnType::Unknown("bignum".to_string()))
+        );
+        assert_eq!(metadata.typed_column("missing"), None);
+    }
+
+    #[test]
+    fn deserialize_rows_maps_into_user_struct() {
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Row {
+            a: String,
+            b: i64,
+        }
+
+        let mut row = HashMap::new();
+        row.insert("a".to_string(), serde_json::json!("hello"));
+        row.insert("b".to_string(), serde_json::json!(42));
+
+        let result = ResultData {
+            metadata: metadata(),
+            rows: vec![row],
+        };
+
+        let rows: Vec<Row> = result.deserialize_rows().unwrap();
+        assert_eq!(
+            rows,
+            vec![Row {
+                a: "hello".to_string(),
+                b: 42
+            }]
+        );
+    }
+
+    fn csv_metadata() -> ResultMetadata {
+        ResultMetadata {
+            column_names: vec![
+                "name".to_string(),
+                "count".to_string(),
+                "flag".to_string(),
+                "note".to_string(),
+            ],
+            column_types: vec![
+                "varchar".to_string(),
+                "integer".to_string(),
+                "boolean".to_string(),
+                "varchar".to_string(),
+            ],
+            total_row_count: 1,
+            datapoint_count: 1,
+            result_set_bytes: None,
+            pending_time_millis: None,
+            execution_time_millis: None,
+        }
+    }
+
+    #[test]
+    fn to_csv_quotes_commas_quotes_and_newlines() {
+        let mut row = HashMap::new();
+        row.insert("name".to_string(), serde_json::json!("a, \"b\"\nc"));
+        row.insert("count".to_string(), serde_json::json!(5));
+        row.insert("flag".to_string(), serde_json::json!(true));
+        row.insert("note".to_string(), serde_json::Value::Null);
+
+        let result = ResultData {
+            metadata: csv_metadata(),
+            rows: vec![row],
+        };
+
+        let mut buf = Vec::new();
+        result.to_csv(&mut buf).unwrap();
+        let csv = String::from_utf8(buf).unwrap();
+
+        assert_eq!(csv, "name,count,flag,note\n\"a, \"\"b\"\"\nc\",5,true,\n");
+    }
+
+    #[test]
+    fn to_ndjson_writes_one_object_per_line() {
+        let mut row1 = HashMap::new();
+        row1.insert("name".to_string(), serde_json::json!("a"));
+        row1.insert("count".to_string(), serde_json::json!(1));
+        row1.insert("flag".to_string(), serde_json::json!(false));
+        row1.insert("note".to_string(), serde_json::Value::Null);
+
+        let mut row2 = HashMap::new();
+        row2.insert("name".to_string(), serde_json::json!("b"));
+        row2.insert("count".to_string(), serde_json::json!(2));
+        row2.insert("flag".to_string(), serde_json::json!(true));
+        row2.insert("note".to_string(), serde_json::json!("hi"));
+
+        let result = ResultData {
+            metadata: csv_metadata(),
+            rows: vec![row1, row2],
+        };
+
+        let mut buf = Vec::new();
+        result.to_ndjson(&mut buf).unwrap();
+        let ndjson = String::from_utf8(buf).unwrap();
+
+        let lines: Vec<&str> = ndjson.lines().collect();
+        assert_eq!(lines.len(), 2);
+        for line in lines {
+            serde_json::from_str::<serde_json::Value>(line).unwrap();
+        }
+    }
+}